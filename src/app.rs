@@ -1,14 +1,35 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::text::Line;
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use tokio::time::{Duration, Instant};
 
-use crate::file_searcher::{FileSearcher, SearchResult};
+use tokio::sync::mpsc;
+
+use crate::bookmarks::{Bookmark, Bookmarks};
+use crate::event::Event;
+use crate::file_searcher::{sort_entries, FileSearcher, SearchResult, SortKey, SortOrder};
+use crate::fuzzy_engine::FuzzyMatch;
+use crate::pager::Pager;
+use crate::preview::Previewer;
+use crate::result_search::SearchState;
+
+/// Which half of a two-keystroke mark sequence (`m<letter>` / `'<letter>`)
+/// we're waiting to complete.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PendingMark {
+    Set,
+    Jump,
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppMode {
     FileBrowser,
     ContentSearch,
+    Preview,
     Help,
 }
 
@@ -16,6 +37,7 @@ pub enum AppMode {
 pub enum InputMode {
     Normal,
     Editing,
+    ResultSearch,
 }
 
 pub struct App {
@@ -31,19 +53,40 @@ pub struct App {
     // Search results with pagination
     pub file_results: Vec<PathBuf>,
     pub content_results: Vec<SearchResult>,
-    
+    // Fuzzy highlight indices for `file_results`, one entry per row; empty
+    // when the listing isn't the result of a fuzzy query.
+    pub file_match_indices: Vec<Vec<usize>>,
+
     // All results (unpaginated for searching through)
     all_file_results: Vec<PathBuf>,
+    all_file_match_indices: Vec<Vec<usize>>,
     all_content_results: Vec<SearchResult>,
+    // Metadata backing `all_file_results` for the plain (non-fuzzy) listing,
+    // kept around so re-sorting doesn't require re-walking the tree. Empty
+    // while a fuzzy query is active, since those results are relevance-sorted.
+    all_file_entries: Vec<crate::file_searcher::FileEntry>,
     
     // Pagination
     pub current_page: usize,
     pub page_size: usize,
     pub total_pages: usize,
+
+    // Active sort for the plain (non-fuzzy) file listing
+    pub sort_key: SortKey,
+    pub sort_order: SortOrder,
     
     // File searcher
-    file_searcher: FileSearcher,
-    
+    file_searcher: Arc<FileSearcher>,
+
+    // Plumbing for the streaming content search: a sender back into the
+    // main event channel, a flag to cancel an in-flight search that's been
+    // superseded or dismissed, and a generation counter so batches from a
+    // cancelled search that were already mid-flight get dropped instead of
+    // polluting the results of whatever search replaced it.
+    event_tx: mpsc::UnboundedSender<Event>,
+    content_search_cancel: Arc<AtomicBool>,
+    content_search_generation: u64,
+
     // Debouncing for live search
     last_search_time: Instant,
     search_debounce_duration: Duration,
@@ -51,12 +94,55 @@ pub struct App {
     // Async search state
     pub is_searching: bool,
     pub search_progress: String,
+
+    // Preview pane
+    previewer: Previewer,
+    pub preview_path: Option<PathBuf>,
+    pub preview_lines: Arc<Vec<Line<'static>>>,
+    pub preview_scroll: usize,
+    preview_return_mode: AppMode,
+    pub pager: Option<Pager>,
+
+    // Always-on side preview next to the file/content list, debounced so it
+    // doesn't reload on every single keystroke while scrolling fast.
+    pub side_preview_path: Option<PathBuf>,
+    pub side_preview_lines: Arc<Vec<Line<'static>>>,
+    pub side_preview_scroll: usize,
+    // The (path, matched-line) key the side preview was last rendered for,
+    // and the one it's currently debouncing toward — kept separate from
+    // `side_preview_path` because a content-search match can move between
+    // two lines in the same file without the path itself changing.
+    side_preview_target: Option<(PathBuf, Option<usize>)>,
+    pending_preview_key: Option<(PathBuf, Option<usize>)>,
+    last_selection_time: Instant,
+    preview_debounce_duration: Duration,
+
+    // In-results incremental search (n/N navigation)
+    pub result_search: SearchState,
+
+    // Multi-select across the loaded result set
+    pub selected_paths: HashSet<PathBuf>,
+    pub output_paths: Vec<PathBuf>,
+
+    // Live filesystem watching: the actual watch task lives in EventHandler
+    // (it pushes debounced Event::FsChanged), this is just the shared
+    // pause flag so the UI can show/toggle watch status.
+    watch_paused: Option<Arc<AtomicBool>>,
+
+    // Bookmarked locations (bk-style marks/jumps)
+    bookmarks: Bookmarks,
+    pending_mark: Option<PendingMark>,
 }
 
 impl App {
-    pub async fn new(directory: PathBuf, initial_pattern: Option<String>) -> Result<Self> {
-        let file_searcher = FileSearcher::new(directory.clone())?;
-        
+    pub async fn new(
+        directory: PathBuf,
+        initial_pattern: Option<String>,
+        watch_paused: Option<Arc<AtomicBool>>,
+        event_tx: mpsc::UnboundedSender<Event>,
+    ) -> Result<Self> {
+        let file_searcher = Arc::new(FileSearcher::new(directory.clone())?);
+
         let mut app = Self {
             should_quit: false,
             mode: AppMode::FileBrowser,
@@ -68,16 +154,43 @@ impl App {
             scroll_offset: 0,
             file_results: Vec::new(),
             content_results: Vec::new(),
+            file_match_indices: Vec::new(),
             all_file_results: Vec::new(),
+            all_file_match_indices: Vec::new(),
             all_content_results: Vec::new(),
+            all_file_entries: Vec::new(),
             current_page: 0,
             page_size: 1000, // Items per page
             total_pages: 0,
+            sort_key: SortKey::Name,
+            sort_order: SortOrder::Ascending,
             file_searcher,
+            event_tx,
+            content_search_cancel: Arc::new(AtomicBool::new(false)),
+            content_search_generation: 0,
             last_search_time: Instant::now(),
             search_debounce_duration: Duration::from_millis(150),
             is_searching: false,
             search_progress: String::new(),
+            previewer: Previewer::new(),
+            preview_path: None,
+            preview_lines: Arc::new(Vec::new()),
+            preview_scroll: 0,
+            preview_return_mode: AppMode::FileBrowser,
+            pager: None,
+            side_preview_path: None,
+            side_preview_lines: Arc::new(Vec::new()),
+            side_preview_scroll: 0,
+            side_preview_target: None,
+            pending_preview_key: None,
+            last_selection_time: Instant::now(),
+            preview_debounce_duration: Duration::from_millis(150),
+            result_search: SearchState::new(),
+            selected_paths: HashSet::new(),
+            output_paths: Vec::new(),
+            watch_paused,
+            bookmarks: Bookmarks::new(),
+            pending_mark: None,
         };
         
         // Initial file listing
@@ -90,10 +203,27 @@ impl App {
         match self.input_mode {
             InputMode::Normal => self.handle_normal_mode(key_event).await,
             InputMode::Editing => self.handle_editing_mode(key_event).await,
+            InputMode::ResultSearch => self.handle_result_search_mode(key_event).await,
         }
     }
     
     async fn handle_normal_mode(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if self.mode == AppMode::Preview {
+            return self.handle_preview_mode(key_event).await;
+        }
+
+        // A pending 'm' or '\'' consumes the very next key as the mark
+        // letter, regardless of what it's otherwise bound to.
+        if let Some(action) = self.pending_mark.take() {
+            if let KeyCode::Char(letter) = key_event.code {
+                match action {
+                    PendingMark::Set => self.set_bookmark(letter),
+                    PendingMark::Jump => self.jump_to_bookmark(letter),
+                }
+            }
+            return Ok(false);
+        }
+
         match key_event.code {
             KeyCode::Char('q') => return Ok(true),
             KeyCode::Char('h') | KeyCode::F(1) => {
@@ -104,12 +234,17 @@ impl App {
                 };
             }
             KeyCode::Tab => {
+                if self.mode == AppMode::ContentSearch {
+                    self.cancel_content_search();
+                    self.finish_content_search(self.content_search_generation);
+                }
                 self.mode = match self.mode {
                     AppMode::FileBrowser => AppMode::ContentSearch,
                     AppMode::ContentSearch => AppMode::FileBrowser,
-                    AppMode::Help => AppMode::FileBrowser,
+                    AppMode::Preview | AppMode::Help => AppMode::FileBrowser,
                 };
                 self.reset_selection();
+                self.result_search.clear();
             }
             KeyCode::Char('/') => {
                 self.input_mode = InputMode::Editing;
@@ -123,20 +258,66 @@ impl App {
                         AppMode::ContentSearch => self.search_content().await?,
                         _ => {}
                     }
+                } else {
+                    self.open_preview().await?;
                 }
             }
+            KeyCode::Char(' ') => {
+                self.toggle_selection();
+            }
+            KeyCode::Char('i') => {
+                self.invert_selection();
+            }
+            KeyCode::Char('c') => {
+                self.clear_selection();
+            }
+            KeyCode::Char('d') => {
+                self.trash_selected().await?;
+            }
+            KeyCode::Char('y') => {
+                self.copy_selection_to_output();
+            }
             KeyCode::Char('r') => {
                 // Refresh/reload files
                 self.file_searcher.invalidate_caches().await;
                 self.refresh_files().await?;
             }
+            KeyCode::Char('w') => {
+                self.toggle_watching();
+            }
+            KeyCode::Char('s') => {
+                self.sort_key = self.sort_key.cycle();
+                self.resort_files();
+            }
+            KeyCode::Char('S') => {
+                self.sort_order = self.sort_order.toggle();
+                self.resort_files();
+            }
+            KeyCode::Char('m') => {
+                self.pending_mark = Some(PendingMark::Set);
+            }
+            KeyCode::Char('\'') | KeyCode::Char('`') => {
+                self.pending_mark = Some(PendingMark::Jump);
+            }
             // Pagination controls
-            KeyCode::Char('n') | KeyCode::Char(']') => {
+            KeyCode::Char(']') => {
                 self.next_page();
             }
-            KeyCode::Char('p') | KeyCode::Char('[') => {
+            KeyCode::Char('[') => {
                 self.prev_page();
             }
+            KeyCode::Char('f') => {
+                self.input_mode = InputMode::ResultSearch;
+                self.result_search.clear();
+            }
+            KeyCode::Char('n') => {
+                self.result_search.advance();
+                self.jump_to_current_match();
+            }
+            KeyCode::Char('N') => {
+                self.result_search.retreat();
+                self.jump_to_current_match();
+            }
             KeyCode::Char('g') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.first_page();
             }
@@ -191,6 +372,10 @@ impl App {
                 }
             }
             KeyCode::Esc => {
+                if self.mode == AppMode::ContentSearch {
+                    self.cancel_content_search();
+                    self.finish_content_search(self.content_search_generation);
+                }
                 self.input_mode = InputMode::Normal;
             }
             KeyCode::Char(c) => {
@@ -232,6 +417,161 @@ impl App {
         Ok(false)
     }
     
+    async fn handle_result_search_mode(&mut self, key_event: KeyEvent) -> Result<bool> {
+        match key_event.code {
+            KeyCode::Enter | KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+            }
+            KeyCode::Char(c) => {
+                self.result_search.term.push(c);
+                self.recompute_result_search();
+            }
+            KeyCode::Backspace => {
+                self.result_search.term.pop();
+                self.recompute_result_search();
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn current_result_rows(&self) -> Vec<String> {
+        match self.mode {
+            AppMode::FileBrowser => self
+                .all_file_results
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            AppMode::ContentSearch => self
+                .all_content_results
+                .iter()
+                .map(|result| result.line_content.clone())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn recompute_result_search(&mut self) {
+        let term = self.result_search.term.clone();
+        let rows = self.current_result_rows();
+        self.result_search.recompute(&term, rows.iter().map(String::as_str));
+        self.jump_to_current_match();
+    }
+
+    /// Moves the page/selection to the active match, wrapping pages as needed.
+    fn jump_to_current_match(&mut self) {
+        let result_index = match self.result_search.current() {
+            Some(m) => m.result_index,
+            None => return,
+        };
+
+        let page_size = self.page_size.max(1);
+        let target_page = result_index / page_size;
+        if target_page != self.current_page {
+            self.current_page = target_page;
+            self.update_current_page_results();
+        }
+        self.selected_index = result_index % page_size;
+        self.adjust_scroll();
+    }
+
+    async fn handle_preview_mode(&mut self, key_event: KeyEvent) -> Result<bool> {
+        match key_event.code {
+            KeyCode::Char('q') => return Ok(true),
+            KeyCode::Esc | KeyCode::Char('h') | KeyCode::F(1) => {
+                self.mode = self.preview_return_mode.clone();
+                self.pager = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_preview(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_preview(1),
+            KeyCode::PageUp => self.scroll_preview(-20),
+            KeyCode::PageDown => self.scroll_preview(20),
+            KeyCode::Home => {
+                if let Some(pager) = self.pager.as_mut() {
+                    pager.go_home();
+                } else {
+                    self.preview_scroll = 0;
+                }
+            }
+            KeyCode::End => {
+                if let Some(pager) = self.pager.as_mut() {
+                    pager.go_end();
+                } else {
+                    self.preview_scroll = self.preview_lines.len().saturating_sub(1);
+                }
+            }
+            KeyCode::Left => {
+                if let Some(pager) = self.pager.as_mut() {
+                    pager.scroll_left(4);
+                }
+            }
+            KeyCode::Right => {
+                if let Some(pager) = self.pager.as_mut() {
+                    pager.scroll_right(4);
+                }
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn scroll_preview(&mut self, delta: i64) {
+        if let Some(pager) = self.pager.as_mut() {
+            if delta < 0 {
+                pager.scroll_up(delta.unsigned_abs() as usize);
+            } else {
+                pager.scroll_down(delta as usize);
+            }
+        } else if delta < 0 {
+            self.preview_scroll = self.preview_scroll.saturating_sub(delta.unsigned_abs() as usize);
+        } else {
+            self.preview_scroll = (self.preview_scroll + delta as usize)
+                .min(self.preview_lines.len().saturating_sub(1));
+        }
+    }
+
+    /// Opens the preview for the currently selected item: a syntax-highlighted
+    /// view of the whole file from the file browser, or a reflowing pager
+    /// scoped to the lines around the match from content search.
+    async fn open_preview(&mut self) -> Result<()> {
+        match self.mode {
+            AppMode::FileBrowser => {
+                let relative_path = match self.get_current_file() {
+                    Some(path) => path.clone(),
+                    None => return Ok(()),
+                };
+                let full_path = self.current_directory.join(&relative_path);
+
+                self.pager = None;
+                self.preview_lines = self.previewer.highlight_file(&full_path).await?;
+                self.preview_scroll = 0;
+                self.preview_path = Some(full_path);
+                self.preview_return_mode = AppMode::FileBrowser;
+                self.mode = AppMode::Preview;
+            }
+            AppMode::ContentSearch => {
+                let result = match self.get_current_content_result() {
+                    Some(result) => result.clone(),
+                    None => return Ok(()),
+                };
+                let full_path = self.current_directory.join(&result.file_path);
+                let (context_text, match_offset) = self
+                    .file_searcher
+                    .read_context(&result.file_path, result.line_number, 20)
+                    .await?;
+
+                let mut pager = Pager::new(context_text);
+                pager.jump_to_line(match_offset);
+                self.pager = Some(pager);
+                self.preview_path = Some(full_path);
+                self.preview_return_mode = AppMode::ContentSearch;
+                self.mode = AppMode::Preview;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     async fn schedule_debounced_search(&mut self) -> Result<()> {
         // Simple debouncing - we'll check in tick() if enough time has passed
         Ok(())
@@ -240,17 +580,65 @@ impl App {
     async fn refresh_files(&mut self) -> Result<()> {
         self.is_searching = true;
         self.search_progress = "Loading files...".to_string();
-        
-        let files = self.file_searcher.list_files().await?;
-        self.all_file_results = files;
-        
+
+        let previous_path = self.get_current_file().cloned();
+
+        let mut entries = self.file_searcher.list_files().await?;
+        sort_entries(&mut entries, self.sort_key, self.sort_order);
+        self.all_file_results = entries.iter().map(|entry| entry.path.clone()).collect();
+        self.all_file_match_indices = vec![Vec::new(); self.all_file_results.len()];
+        self.all_file_entries = entries;
+
+        let still_present: HashSet<&PathBuf> = self.all_file_results.iter().collect();
+        self.selected_paths.retain(|path| still_present.contains(path));
+
         self.update_pagination();
-        self.reset_selection();
+        self.result_search.clear();
+        self.restore_selection_by_path(previous_path);
         self.is_searching = false;
         self.search_progress.clear();
         Ok(())
     }
-    
+
+    /// Restores the selection to `path` if it still exists in the current
+    /// listing (switching pages if needed), falling back to the top of the
+    /// list otherwise.
+    fn restore_selection_by_path(&mut self, path: Option<PathBuf>) {
+        let position = path.and_then(|path| self.all_file_results.iter().position(|p| *p == path));
+
+        match position {
+            Some(global_index) => {
+                let page_size = self.page_size.max(1);
+                self.current_page = global_index / page_size;
+                self.update_current_page_results();
+                self.selected_index = global_index % page_size;
+                self.adjust_scroll();
+            }
+            None => self.reset_selection(),
+        }
+    }
+
+    /// Re-sorts the already-loaded plain listing in place using the cached
+    /// metadata, so changing sort key/order doesn't require re-walking the
+    /// tree. A no-op while a fuzzy query's relevance-sorted results are
+    /// showing (`all_file_entries` is cleared in that case).
+    fn resort_files(&mut self) {
+        if self.all_file_entries.is_empty() {
+            return;
+        }
+
+        let previous_path = self.get_current_file().cloned();
+
+        let mut entries = self.all_file_entries.clone();
+        sort_entries(&mut entries, self.sort_key, self.sort_order);
+        self.all_file_results = entries.iter().map(|entry| entry.path.clone()).collect();
+        self.all_file_match_indices = vec![Vec::new(); self.all_file_results.len()];
+        self.all_file_entries = entries;
+
+        self.update_pagination();
+        self.restore_selection_by_path(previous_path);
+    }
+
     async fn search_files(&mut self) -> Result<()> {
         if self.search_query.is_empty() {
             self.refresh_files().await?;
@@ -260,35 +648,88 @@ impl App {
         self.is_searching = true;
         self.search_progress = format!("Searching files for '{}'...", self.search_query);
         
-        let results = self.file_searcher.fuzzy_search_files(&self.search_query).await?;
-        self.all_file_results = results;
-        
+        let matches = self.file_searcher.fuzzy_search_files(&self.search_query).await?;
+        let (paths, indices): (Vec<PathBuf>, Vec<Vec<usize>>) = matches
+            .into_iter()
+            .map(|FuzzyMatch { path, indices, .. }| (path, indices))
+            .unzip();
+        self.all_file_results = paths;
+        self.all_file_match_indices = indices;
+        self.all_file_entries.clear();
+
         self.update_pagination();
         self.reset_selection();
+        self.result_search.clear();
         self.is_searching = false;
         self.search_progress.clear();
         Ok(())
     }
-    
+
+    /// Kicks off a streaming content search: results arrive as
+    /// `Event::SearchResults` batches (see `FileSearcher::search_content_streaming`)
+    /// and get appended by `append_search_results`, so the list fills in as
+    /// the scan progresses instead of blocking until it's done.
     async fn search_content(&mut self) -> Result<()> {
+        self.cancel_content_search();
+
+        self.all_content_results.clear();
+        self.update_pagination();
+        self.reset_selection();
+        self.result_search.clear();
+
         if self.search_query.is_empty() {
-            self.all_content_results.clear();
-            self.update_pagination();
-            self.reset_selection();
             return Ok(());
         }
-        
+
         self.is_searching = true;
         self.search_progress = format!("Searching content for '{}'...", self.search_query);
-        
-        let results = self.file_searcher.search_content(&self.search_query).await?;
-        self.all_content_results = results;
-        
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.content_search_cancel = Arc::clone(&cancel);
+        let generation = self.content_search_generation;
+
+        let file_searcher = Arc::clone(&self.file_searcher);
+        let query = self.search_query.clone();
+        let event_tx = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            let _ = file_searcher
+                .search_content_streaming(&query, event_tx, cancel, generation)
+                .await;
+        });
+
+        Ok(())
+    }
+
+    /// Trips the cancellation flag for any in-flight streaming content
+    /// search and bumps its generation, so a chunk that was already
+    /// mid-flight when cancellation landed gets its batch dropped by
+    /// `append_search_results`/`finish_content_search` instead of being
+    /// attributed to whatever search (if any) replaces it.
+    fn cancel_content_search(&mut self) {
+        self.content_search_cancel.store(true, Ordering::Relaxed);
+        self.content_search_generation += 1;
+    }
+
+    /// Appends a batch of streamed content-search results, unless `generation`
+    /// belongs to a search that's since been cancelled or superseded (a batch
+    /// can still be in flight when that happens, since cancellation is only
+    /// checked between chunks) — such stale batches are dropped rather than
+    /// polluting whatever search replaced them.
+    pub fn append_search_results(&mut self, generation: u64, mut results: Vec<SearchResult>) {
+        if generation != self.content_search_generation {
+            return;
+        }
+        self.all_content_results.append(&mut results);
         self.update_pagination();
-        self.reset_selection();
+    }
+
+    pub fn finish_content_search(&mut self, generation: u64) {
+        if generation != self.content_search_generation {
+            return;
+        }
         self.is_searching = false;
         self.search_progress.clear();
-        Ok(())
     }
     
     fn update_pagination(&mut self) {
@@ -319,6 +760,7 @@ impl App {
             AppMode::FileBrowser => {
                 let end_idx = ((start_idx + self.page_size).min(self.all_file_results.len())).max(start_idx);
                 self.file_results = self.all_file_results[start_idx..end_idx].to_vec();
+                self.file_match_indices = self.all_file_match_indices[start_idx..end_idx].to_vec();
             }
             AppMode::ContentSearch => {
                 let end_idx = ((start_idx + self.page_size).min(self.all_content_results.len())).max(start_idx);
@@ -399,10 +841,151 @@ impl App {
                 self.search_files().await?;
             }
         }
-        
+
+        self.update_side_preview().await?;
+
         Ok(())
     }
-    
+
+    /// The path (and, in `ContentSearch` mode, the matched line number) that
+    /// the side preview should currently be showing.
+    fn get_current_preview_target(&self) -> Option<(PathBuf, Option<usize>)> {
+        match self.mode {
+            AppMode::FileBrowser => self.get_current_file().map(|path| (path.clone(), None)),
+            AppMode::ContentSearch => self
+                .get_current_content_result()
+                .map(|result| (result.file_path.clone(), Some(result.line_number))),
+            _ => None,
+        }
+    }
+
+    /// Keeps the always-on side preview in sync with the current selection,
+    /// debounced so rapid scrolling doesn't trigger a reload per row. A file
+    /// that has vanished or become unreadable (e.g. trashed, or removed by
+    /// an external process the fs watcher just caught) shows an inline
+    /// notice instead of propagating the I/O error out of `tick()`.
+    async fn update_side_preview(&mut self) -> Result<()> {
+        let current = self.get_current_preview_target();
+
+        if current != self.pending_preview_key {
+            self.pending_preview_key = current;
+            self.last_selection_time = Instant::now();
+            return Ok(());
+        }
+
+        if current == self.side_preview_target {
+            return Ok(());
+        }
+
+        if self.last_selection_time.elapsed() < self.preview_debounce_duration {
+            return Ok(());
+        }
+
+        match current.clone() {
+            Some((path, line_number)) => {
+                let full_path = self.current_directory.join(&path);
+                match self.previewer.highlight_file(&full_path).await {
+                    Ok(lines) => self.side_preview_lines = lines,
+                    Err(_) => {
+                        self.side_preview_lines =
+                            Arc::new(vec![Line::from("<file no longer available>")]);
+                    }
+                }
+                self.side_preview_path = Some(path);
+                // Scroll so the matched line lands a little below the top
+                // of the pane instead of requiring the user to scroll down
+                // to it manually.
+                self.side_preview_scroll = line_number
+                    .map(|line| line.saturating_sub(1).saturating_sub(10))
+                    .unwrap_or(0)
+                    .min(self.side_preview_lines.len().saturating_sub(1));
+            }
+            None => {
+                self.side_preview_lines = Arc::new(Vec::new());
+                self.side_preview_path = None;
+                self.side_preview_scroll = 0;
+            }
+        }
+        self.side_preview_target = current;
+        Ok(())
+    }
+
+    /// Handles `Event::FsChanged`: the watcher in `EventHandler` already
+    /// debounced the burst of writes, so just invalidate caches built on the
+    /// stale listing and re-run the current file query (the fuzzy search if
+    /// one is active, otherwise the plain listing). Content-search results
+    /// are not re-run; they stay as they are until the next content search.
+    pub async fn handle_fs_changed(&mut self) -> Result<()> {
+        self.file_searcher.invalidate_caches().await;
+        if self.mode == AppMode::FileBrowser && !self.search_query.is_empty() {
+            self.search_files().await?;
+        } else {
+            self.refresh_files().await?;
+        }
+        Ok(())
+    }
+
+    /// Stores the current location under `key`, resolving it back by path
+    /// on jump so the mark survives list reordering.
+    fn set_bookmark(&mut self, key: char) {
+        let bookmark = Bookmark {
+            mode: self.mode.clone(),
+            current_page: self.current_page,
+            selected_index: self.selected_index,
+            scroll_offset: self.scroll_offset,
+            path: self.get_current_selectable_path(),
+        };
+        self.bookmarks.set(key, bookmark);
+    }
+
+    /// Jumps back to the location stored under `key`, if any. Prefers
+    /// resolving the bookmarked path in the current listing; falls back to
+    /// the stored page/index when the path is gone.
+    fn jump_to_bookmark(&mut self, key: char) {
+        let bookmark = match self.bookmarks.get(key) {
+            Some(bookmark) => bookmark.clone(),
+            None => return,
+        };
+
+        self.mode = bookmark.mode.clone();
+
+        let resolved_index = bookmark.path.as_ref().and_then(|path| match bookmark.mode {
+            AppMode::FileBrowser => self.all_file_results.iter().position(|p| p == path),
+            AppMode::ContentSearch => {
+                self.all_content_results.iter().position(|r| &r.file_path == path)
+            }
+            _ => None,
+        });
+
+        let page_size = self.page_size.max(1);
+        match resolved_index {
+            Some(global_index) => {
+                self.current_page = global_index / page_size;
+                self.update_current_page_results();
+                self.selected_index = global_index % page_size;
+            }
+            None => {
+                self.current_page = bookmark.current_page.min(self.total_pages.saturating_sub(1));
+                self.update_current_page_results();
+                self.selected_index = bookmark.selected_index;
+            }
+        }
+
+        self.scroll_offset = bookmark.scroll_offset;
+        self.adjust_scroll();
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.watch_paused.as_ref().is_some_and(|paused| !paused.load(Ordering::Relaxed))
+    }
+
+    fn toggle_watching(&mut self) {
+        if let Some(paused) = self.watch_paused.as_ref() {
+            let new_state = !paused.load(Ordering::Relaxed);
+            paused.store(new_state, Ordering::Relaxed);
+        }
+    }
+
     pub fn get_visible_items(&self) -> (usize, usize) {
         let total_items = self.get_current_page_items_len();
         (self.scroll_offset, total_items)
@@ -424,11 +1007,121 @@ impl App {
         }
     }
     
+    fn get_current_selectable_path(&self) -> Option<PathBuf> {
+        match self.mode {
+            AppMode::FileBrowser => self.get_current_file().cloned(),
+            AppMode::ContentSearch => self.get_current_content_result().map(|r| r.file_path.clone()),
+            _ => None,
+        }
+    }
+
+    fn all_selectable_paths(&self) -> Vec<PathBuf> {
+        match self.mode {
+            AppMode::FileBrowser => self.all_file_results.clone(),
+            AppMode::ContentSearch => {
+                let mut paths: Vec<PathBuf> =
+                    self.all_content_results.iter().map(|r| r.file_path.clone()).collect();
+                paths.sort();
+                paths.dedup();
+                paths
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn toggle_selection(&mut self) {
+        if let Some(path) = self.get_current_selectable_path() {
+            if !self.selected_paths.remove(&path) {
+                self.selected_paths.insert(path);
+            }
+        }
+    }
+
+    fn invert_selection(&mut self) {
+        for path in self.all_selectable_paths() {
+            if !self.selected_paths.remove(&path) {
+                self.selected_paths.insert(path);
+            }
+        }
+    }
+
+    fn clear_selection(&mut self) {
+        self.selected_paths.clear();
+    }
+
+    /// Moves every selected file to the trash, then refreshes the listing.
+    async fn trash_selected(&mut self) -> Result<()> {
+        if self.selected_paths.is_empty() {
+            return Ok(());
+        }
+
+        let paths: Vec<PathBuf> = self
+            .selected_paths
+            .iter()
+            .map(|relative| self.current_directory.join(relative))
+            .collect();
+
+        tokio::task::spawn_blocking(move || {
+            for path in &paths {
+                let _ = trash::delete(path);
+            }
+        })
+        .await?;
+
+        self.selected_paths.clear();
+        self.file_searcher.invalidate_caches().await;
+        self.refresh_files().await?;
+        Ok(())
+    }
+
+    /// Queues the selection's absolute paths (or the item under the cursor,
+    /// if nothing is selected) to be printed when the app exits.
+    fn copy_selection_to_output(&mut self) {
+        let mut paths: Vec<PathBuf> = if self.selected_paths.is_empty() {
+            self.get_current_selectable_path().into_iter().collect()
+        } else {
+            self.selected_paths.iter().cloned().collect()
+        };
+        paths.sort();
+
+        for relative in paths.drain(..) {
+            let absolute = self.current_directory.join(&relative);
+            if !self.output_paths.contains(&absolute) {
+                self.output_paths.push(absolute);
+            }
+        }
+    }
+
     pub fn get_status_info(&self) -> String {
         if self.is_searching && !self.search_progress.is_empty() {
+            if self.mode == AppMode::ContentSearch {
+                let (scanned, total) = self.file_searcher.content_scan_progress();
+                if total > 0 {
+                    return format!("{} ({}/{})", self.search_progress, scanned, total);
+                }
+            }
             return self.search_progress.clone();
         }
-        
+
+        let mut info = self.get_status_info_base();
+        if self.mode == AppMode::FileBrowser && !self.all_file_entries.is_empty() {
+            info = format!(
+                "{} | Sort: {} ({})",
+                info,
+                self.sort_key.label(),
+                self.sort_order.label()
+            );
+        }
+        if !self.selected_paths.is_empty() {
+            info = format!("{} | {} selected", info, self.selected_paths.len());
+        }
+        if let Some(match_status) = self.result_search.status() {
+            info = format!("{} | {}", info, match_status);
+        }
+        info
+    }
+
+    fn get_status_info_base(&self) -> String {
         let total_items = match self.mode {
             AppMode::FileBrowser => self.all_file_results.len(),
             AppMode::ContentSearch => self.all_content_results.len(),
@@ -439,6 +1132,11 @@ impl App {
             match self.mode {
                 AppMode::FileBrowser => format!("Files: {}", total_items),
                 AppMode::ContentSearch => format!("Results: {}", total_items),
+                AppMode::Preview => self
+                    .preview_path
+                    .as_ref()
+                    .map(|p| format!("Preview: {}", p.display()))
+                    .unwrap_or_else(|| "Preview".to_string()),
                 AppMode::Help => "Help".to_string(),
             }
         } else {
@@ -456,6 +1154,11 @@ impl App {
                     start_item, end_item, total_items, 
                     self.current_page + 1, self.total_pages
                 ),
+                AppMode::Preview => self
+                    .preview_path
+                    .as_ref()
+                    .map(|p| format!("Preview: {}", p.display()))
+                    .unwrap_or_else(|| "Preview".to_string()),
                 AppMode::Help => "Help".to_string(),
             }
         }
@@ -465,7 +1168,7 @@ impl App {
         if self.total_pages <= 1 {
             String::new()
         } else {
-            format!("Page {}/{} | n/]: Next | p/[: Prev | Ctrl+g: First | G: Last", 
+            format!("Page {}/{} | ]: Next | [: Prev | Ctrl+g: First | G: Last",
                     self.current_page + 1, self.total_pages)
         }
     }
@@ -0,0 +1,34 @@
+use crate::app::AppMode;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A saved cursor position, keyed by the letter the user marked it with.
+#[derive(Debug, Clone)]
+pub struct Bookmark {
+    pub mode: AppMode,
+    pub current_page: usize,
+    pub selected_index: usize,
+    pub scroll_offset: usize,
+    // Resolved by path first on jump, so the mark survives reordering;
+    // the page/index above are only a fallback.
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+pub struct Bookmarks {
+    marks: HashMap<char, Bookmark>,
+}
+
+impl Bookmarks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: char, bookmark: Bookmark) {
+        self.marks.insert(key, bookmark);
+    }
+
+    pub fn get(&self, key: char) -> Option<&Bookmark> {
+        self.marks.get(&key)
+    }
+}
@@ -1,27 +1,77 @@
 use anyhow::Result;
 use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent, KeyEventKind};
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::time::interval;
 
-#[derive(Clone, Copy, Debug)]
+use crate::file_searcher::SearchResult;
+use crate::watcher::DirWatcher;
+
+#[derive(Clone, Debug)]
 pub enum Event {
     Tick,
     Key(KeyEvent),
     Mouse(MouseEvent),
     Resize(u16, u16),
+    /// The watched directory settled after a burst of filesystem changes;
+    /// the current listing/query should be re-run.
+    FsChanged,
+    /// A batch of content-search matches, streamed in as they're found.
+    /// Tagged with the generation of the search that produced it, so a
+    /// batch from a cancelled/superseded search can be dropped instead of
+    /// being appended to a newer query's results.
+    SearchResults(u64, Vec<SearchResult>),
+    /// The in-flight streaming content search has finished and has no more
+    /// batches to send, tagged with its generation for the same reason as
+    /// `SearchResults`. A cancelled search stops without sending this.
+    SearchComplete(u64),
 }
 
 pub struct EventHandler {
     rx: mpsc::UnboundedReceiver<Event>,
-    _tx: mpsc::UnboundedSender<Event>,
+    tx: mpsc::UnboundedSender<Event>,
+    watch_paused: Option<Arc<AtomicBool>>,
 }
 
 impl EventHandler {
-    pub fn new(tick_rate: u64) -> Self {
+    pub fn new(tick_rate: u64, watch_root: PathBuf) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
         let tick_tx = tx.clone();
 
+        // Watching is best-effort: on platforms/filesystems where it fails
+        // to start, fall back to the manual 'r' refresh instead of erroring out.
+        let watcher = DirWatcher::new(&watch_root).ok();
+        let watch_paused = watcher.as_ref().map(DirWatcher::paused_flag);
+
+        if let Some(mut watcher) = watcher {
+            let fs_tx = tx.clone();
+            tokio::spawn(async move {
+                let debounce = Duration::from_millis(200);
+                let mut poll_interval = interval(Duration::from_millis(50));
+                let mut dirty = false;
+                let mut last_event = tokio::time::Instant::now();
+
+                loop {
+                    poll_interval.tick().await;
+
+                    if !watcher.drain().is_empty() {
+                        dirty = true;
+                        last_event = tokio::time::Instant::now();
+                    }
+
+                    if dirty && last_event.elapsed() >= debounce {
+                        dirty = false;
+                        if fs_tx.send(Event::FsChanged).is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+
         let tx_clone = tx.clone();
         tokio::spawn(async move {
             loop {
@@ -65,7 +115,7 @@ impl EventHandler {
             }
         });
 
-        Self { rx, _tx: tx }
+        Self { rx, tx, watch_paused }
     }
 
     pub async fn next(&mut self) -> Result<Event> {
@@ -74,4 +124,16 @@ impl EventHandler {
             .await
             .ok_or_else(|| anyhow::anyhow!("Event channel closed"))
     }
+
+    /// A shared handle to the watcher's pause flag, so the app can expose a
+    /// watch on/off indicator and toggle key without owning the watcher.
+    pub fn watch_flag(&self) -> Option<Arc<AtomicBool>> {
+        self.watch_paused.clone()
+    }
+
+    /// A sender clone so background tasks (e.g. a streaming content search)
+    /// can push events onto the same channel the main loop reads from.
+    pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
+        self.tx.clone()
+    }
 }
\ No newline at end of file
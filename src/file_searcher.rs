@@ -1,14 +1,14 @@
 use anyhow::Result;
-use fuzzy_matcher::skim::SkimMatcherV2;
-use fuzzy_matcher::FuzzyMatcher;
 use ignore::WalkBuilder;
-use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::SystemTime;
 use tokio::fs;
-use tokio::io::AsyncReadExt;
-use tokio::sync::{Mutex, RwLock, Semaphore};
-use tokio::time::{Duration, Instant};
+use tokio::sync::{mpsc, RwLock, Semaphore};
+
+use crate::event::Event;
+use crate::fuzzy_engine::{FuzzyEngine, FuzzyMatch};
 
 #[derive(Debug, Clone)]
 pub struct SearchResult {
@@ -19,66 +19,159 @@ pub struct SearchResult {
     pub match_end: usize,
 }
 
+/// A listed file plus the metadata needed to sort by size/mtime without
+/// re-`stat`ing every entry on every sort-key change.
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub path: PathBuf,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Name,
+    Size,
+    Modified,
+}
+
+impl SortKey {
+    pub fn cycle(self) -> Self {
+        match self {
+            SortKey::Name => SortKey::Size,
+            SortKey::Size => SortKey::Modified,
+            SortKey::Modified => SortKey::Name,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortKey::Name => "Name",
+            SortKey::Size => "Size",
+            SortKey::Modified => "Modified",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    pub fn toggle(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "asc",
+            SortOrder::Descending => "desc",
+        }
+    }
+}
+
+/// Stable sort by `key`/`order`, always tiebreaking on name so equal-size or
+/// equal-mtime files don't reorder between otherwise-identical refreshes.
+pub fn sort_entries(entries: &mut [FileEntry], key: SortKey, order: SortOrder) {
+    entries.sort_by(|a, b| {
+        let primary = match key {
+            SortKey::Name => a.path.cmp(&b.path),
+            SortKey::Size => a.size.cmp(&b.size),
+            SortKey::Modified => a.modified.cmp(&b.modified),
+        };
+        let primary = match order {
+            SortOrder::Ascending => primary,
+            SortOrder::Descending => primary.reverse(),
+        };
+        primary.then_with(|| a.path.cmp(&b.path))
+    });
+}
+
 #[derive(Debug, Clone)]
 struct CachedFileList {
-    files: Vec<PathBuf>,
-    last_updated: Instant,
+    files: Vec<FileEntry>,
 }
 
 pub struct FileSearcher {
     root_directory: PathBuf,
-    matcher: SkimMatcherV2,
-    max_concurrent_reads: Arc<Semaphore>,
-    // Cache for file listings
+    // Long-lived streaming fuzzy matcher; items persist across queries so a
+    // query only has to reparse the pattern, not rescore every file.
+    fuzzy_engine: Arc<StdMutex<FuzzyEngine>>,
+    // Bounds how many file reads are in flight at once. Sized to the CPU
+    // count rather than an arbitrary constant so a huge directory can't
+    // oversubscribe tokio's blocking-thread pool with thousands of
+    // simultaneous `spawn_blocking` reads.
+    io_pool: Arc<Semaphore>,
+    // Ticks for the content-search progress indicator: `files_scanned` out
+    // of `files_total`, updated as each file's read completes.
+    files_scanned: Arc<AtomicUsize>,
+    files_total: Arc<AtomicUsize>,
+    // Cache for file listings. Freshness is maintained by explicit
+    // invalidation (`invalidate_caches`, called from the fs-watcher's
+    // `Event::FsChanged` handler and the manual 'r' refresh) rather than a
+    // time-based expiry, since the watcher already catches changes live.
     cached_files: Arc<RwLock<Option<CachedFileList>>>,
-    // Cache for fuzzy search results
-    fuzzy_cache: Arc<Mutex<HashMap<String, Vec<PathBuf>>>>,
-    cache_duration: Duration,
 }
 
 impl FileSearcher {
     pub fn new(root_directory: PathBuf) -> Result<Self> {
+        let io_pool_size = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+
         Ok(Self {
             root_directory,
-            matcher: SkimMatcherV2::default(),
-            max_concurrent_reads: Arc::new(Semaphore::new(100)), // Increased for better parallelism
+            fuzzy_engine: Arc::new(StdMutex::new(FuzzyEngine::new())),
+            io_pool: Arc::new(Semaphore::new(io_pool_size)),
+            files_scanned: Arc::new(AtomicUsize::new(0)),
+            files_total: Arc::new(AtomicUsize::new(0)),
             cached_files: Arc::new(RwLock::new(None)),
-            fuzzy_cache: Arc::new(Mutex::new(HashMap::new())),
-            cache_duration: Duration::from_secs(30), // Cache for 30 seconds
         })
     }
 
-    // Async file listing with caching
-    pub async fn list_files(&self) -> Result<Vec<PathBuf>> {
-        // Check if we have a valid cache
+    /// Current `(files_scanned, files_total)` for the in-flight (or most
+    /// recently finished) content search, for a footer progress indicator.
+    pub fn content_scan_progress(&self) -> (usize, usize) {
+        (
+            self.files_scanned.load(Ordering::Relaxed),
+            self.files_total.load(Ordering::Relaxed),
+        )
+    }
+
+    // Async file listing, cached until explicitly invalidated.
+    pub async fn list_files(&self) -> Result<Vec<FileEntry>> {
+        // Check if we have a cached listing
         {
             let cache = self.cached_files.read().await;
             if let Some(cached) = cache.as_ref() {
-                if cached.last_updated.elapsed() < self.cache_duration {
-                    return Ok(cached.files.clone());
-                }
+                return Ok(cached.files.clone());
             }
         }
 
-        // Cache is invalid or doesn't exist, rebuild it
+        // No cached listing (or it was invalidated), rebuild it
         let files = self.build_file_list().await?;
-        
+
         // Update cache
         {
             let mut cache = self.cached_files.write().await;
             *cache = Some(CachedFileList {
                 files: files.clone(),
-                last_updated: Instant::now(),
             });
         }
 
         Ok(files)
     }
 
-    async fn build_file_list(&self) -> Result<Vec<PathBuf>> {
+    async fn build_file_list(&self) -> Result<Vec<FileEntry>> {
         // Use tokio::task::spawn_blocking for CPU-intensive file traversal
         let root_dir = self.root_directory.clone();
-        
+        let fuzzy_engine = Arc::clone(&self.fuzzy_engine);
+
         Ok(tokio::task::spawn_blocking(move || {
             let mut files = Vec::new();
 
@@ -92,110 +185,116 @@ impl FileSearcher {
             for entry in walker.filter_map(Result::ok) {
                 let path = entry.path();
                 if path.is_file() {
-                    if let Ok(relative_path) = path.strip_prefix(&root_dir) {
-                        files.push(relative_path.to_path_buf());
-                    } else {
-                        files.push(path.to_path_buf());
-                    }
+                    let relative_path = path
+                        .strip_prefix(&root_dir)
+                        .map(|p| p.to_path_buf())
+                        .unwrap_or_else(|_| path.to_path_buf());
+
+                    let metadata = std::fs::metadata(path).ok();
+                    let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+                    let modified = metadata
+                        .as_ref()
+                        .and_then(|m| m.modified().ok())
+                        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+                    files.push(FileEntry { path: relative_path, size, modified });
                 }
             }
 
-            files.sort_unstable(); // Slightly faster than sort()
+            files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+            // Keep the streaming matcher's item set in sync with the listing.
+            let paths: Vec<PathBuf> = files.iter().map(|f| f.path.clone()).collect();
+            fuzzy_engine.lock().unwrap().repopulate(&paths);
+
             files
         }).await?)
     }
 
-    // Optimized fuzzy search with caching and early termination
-    pub async fn fuzzy_search_files(&self, query: &str) -> Result<Vec<PathBuf>> {
+    // Streaming fuzzy search backed by a long-lived nucleo matcher: no hard
+    // cap on result count and no separate per-query cache, since the engine
+    // itself holds the parsed items and only re-ticks on a new pattern. Each
+    // match carries the byte indices to highlight in the filename.
+    pub async fn fuzzy_search_files(&self, query: &str) -> Result<Vec<FuzzyMatch>> {
         if query.is_empty() {
-            return self.list_files().await;
+            return Ok(self
+                .list_files()
+                .await?
+                .into_iter()
+                .map(|entry| FuzzyMatch { path: entry.path, score: 0, indices: Vec::new() })
+                .collect());
         }
 
-        // Check cache first
-        {
-            let cache = self.fuzzy_cache.lock().await;
-            if let Some(cached_results) = cache.get(query) {
-                return Ok(cached_results.clone());
-            }
-        }
+        // Make sure the engine has seen the current listing at least once.
+        self.list_files().await?;
 
-        let all_files = self.list_files().await?;
-        
-        // Use spawn_blocking for CPU-intensive fuzzy matching
-        let query_str = query.to_string();
-        let matcher = SkimMatcherV2::default(); // Create new matcher since it doesn't implement Clone
-        
-        let results = tokio::task::spawn_blocking(move || {
-            let mut scored_files: Vec<(PathBuf, i64)> = Vec::new();
-            
-            // Process files in chunks to avoid blocking too long
-            const CHUNK_SIZE: usize = 1000;
-            
-            for chunk in all_files.chunks(CHUNK_SIZE) {
-                for file_path in chunk {
-                    let file_str = file_path.to_string_lossy();
-                    if let Some(score) = matcher.fuzzy_match(&file_str, &query_str) {
-                        scored_files.push((file_path.clone(), score));
-                        
-                        // Early termination for very large result sets
-                        if scored_files.len() > 5000 {
-                            break;
-                        }
-                    }
-                }
-                
-                // Yield control periodically
-                if scored_files.len() > CHUNK_SIZE {
-                    std::thread::yield_now();
-                }
-            }
+        let fuzzy_engine = Arc::clone(&self.fuzzy_engine);
+        let query = query.to_string();
 
-            // Sort by score (higher is better) and take top results
-            scored_files.sort_unstable_by(|a, b| b.1.cmp(&a.1));
-            scored_files.truncate(1000); // Limit results to top 1000
-            
-            scored_files.into_iter().map(|(path, _)| path).collect::<Vec<_>>()
+        let results = tokio::task::spawn_blocking(move || {
+            fuzzy_engine.lock().unwrap().search(&query, 50)
         }).await?;
 
-        // Cache the results
-        {
-            let mut cache = self.fuzzy_cache.lock().await;
-            // Limit cache size to prevent memory bloat
-            if cache.len() > 100 {
-                cache.clear();
-            }
-            cache.insert(query.to_string(), results.clone());
-        }
-
         Ok(results)
     }
 
-    // Optimized content search with better concurrency
-    pub async fn search_content(&self, query: &str) -> Result<Vec<SearchResult>> {
-        let files = self.list_files().await?;
-        let mut results = Vec::new();
+    /// Content search that streams results back chunk-by-chunk instead of
+    /// blocking the caller until the whole tree is scanned: each processed
+    /// chunk is pushed onto `tx` as soon as it's ready, so the UI can render
+    /// matches as they're found. `cancel` is checked between chunks so an
+    /// abandoned query (superseded or dismissed) stops promptly instead of
+    /// scanning to completion for nothing. `generation` tags every batch
+    /// (and the final `SearchComplete`) so the receiver can tell a chunk
+    /// that was already mid-flight when `cancel` was tripped apart from a
+    /// batch belonging to the current search, and drop the former.
+    pub async fn search_content_streaming(
+        &self,
+        query: &str,
+        tx: mpsc::UnboundedSender<Event>,
+        cancel: Arc<AtomicBool>,
+        generation: u64,
+    ) -> Result<()> {
+        let files: Vec<PathBuf> = self.list_files().await?.into_iter().map(|f| f.path).collect();
+
+        self.files_total.store(files.len(), Ordering::Relaxed);
+        self.files_scanned.store(0, Ordering::Relaxed);
 
         // Process files in smaller chunks for better responsiveness
         const CHUNK_SIZE: usize = 50;
-        
+        let mut total_found = 0usize;
+
         for chunk in files.chunks(CHUNK_SIZE) {
-            let chunk_results = self.search_content_in_files_parallel(chunk, query).await?;
-            results.extend(chunk_results);
-            
-            // Yield control to allow UI updates
-            tokio::task::yield_now().await;
-            
-            // Early termination if we have too many results
-            if results.len() > 10000 {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+
+            let mut chunk_results = self.search_content_in_files_parallel(chunk, query).await?;
+            chunk_results.sort_unstable_by(|a, b| {
+                a.file_path.cmp(&b.file_path).then(a.line_number.cmp(&b.line_number))
+            });
+
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+
+            total_found += chunk_results.len();
+            if !chunk_results.is_empty()
+                && tx.send(Event::SearchResults(generation, chunk_results)).is_err()
+            {
+                return Ok(());
+            }
+
+            // Early termination if we have found too many results
+            if total_found > 10000 {
                 break;
             }
-        }
 
-        results.sort_unstable_by(|a, b| {
-            a.file_path.cmp(&b.file_path).then(a.line_number.cmp(&b.line_number))
-        });
+            // Yield control to allow UI updates
+            tokio::task::yield_now().await;
+        }
 
-        Ok(results)
+        let _ = tx.send(Event::SearchComplete(generation));
+        Ok(())
     }
 
     async fn search_content_in_files_parallel(
@@ -203,20 +302,24 @@ impl FileSearcher {
         files: &[PathBuf],
         query: &str,
     ) -> Result<Vec<SearchResult>> {
-        let semaphore = Arc::clone(&self.max_concurrent_reads);
+        let io_pool = Arc::clone(&self.io_pool);
+        let files_scanned = Arc::clone(&self.files_scanned);
         let mut tasks = Vec::new();
 
         for file_path in files {
             let full_path = self.root_directory.join(file_path);
             let file_path = file_path.clone();
             let query = query.to_string();
-            let semaphore = Arc::clone(&semaphore);
+            let io_pool = Arc::clone(&io_pool);
+            let files_scanned = Arc::clone(&files_scanned);
 
             let task = tokio::spawn(async move {
-                let _permit = semaphore.acquire().await.map_err(|_| {
-                    anyhow::anyhow!("Failed to acquire semaphore permit")
+                let _permit = io_pool.acquire().await.map_err(|_| {
+                    anyhow::anyhow!("Failed to acquire IO pool permit")
                 })?;
-                search_in_file_optimized(&full_path, &file_path, &query).await
+                let result = search_in_file_optimized(full_path, file_path, query).await;
+                files_scanned.fetch_add(1, Ordering::Relaxed);
+                result
             });
 
             tasks.push(task);
@@ -236,34 +339,62 @@ impl FileSearcher {
         Ok(results)
     }
 
+    /// Reads the lines around `line_number` (1-indexed) in `relative_path`,
+    /// for the content-search pager. Returns the joined context text plus
+    /// the 0-indexed offset of the matched line within it.
+    pub async fn read_context(
+        &self,
+        relative_path: &Path,
+        line_number: usize,
+        context: usize,
+    ) -> Result<(String, usize)> {
+        let full_path = self.root_directory.join(relative_path);
+        let content = fs::read_to_string(&full_path).await.unwrap_or_default();
+        let lines: Vec<&str> = content.lines().collect();
+
+        if lines.is_empty() {
+            return Ok((String::new(), 0));
+        }
+
+        let match_idx = line_number.saturating_sub(1).min(lines.len() - 1);
+        let start = match_idx.saturating_sub(context);
+        let end = (match_idx + context + 1).min(lines.len());
+
+        Ok((lines[start..end].join("\n"), match_idx - start))
+    }
+
     // Method to invalidate caches when needed
     pub async fn invalidate_caches(&self) {
-        {
-            let mut file_cache = self.cached_files.write().await;
-            *file_cache = None;
-        }
-        {
-            let mut fuzzy_cache = self.fuzzy_cache.lock().await;
-            fuzzy_cache.clear();
-        }
+        let mut file_cache = self.cached_files.write().await;
+        *file_cache = None;
     }
 }
 
-// Optimized file search function
+// Reads and scans one file with blocking IO on tokio's shared blocking-thread
+// pool, rather than tokio's async file IO. There is no separate IO thread
+// pool: callers hold a `FileSearcher::io_pool` permit around this call, which
+// caps how many of these reads run at once at the CPU count.
 async fn search_in_file_optimized(
-    full_path: &Path,
-    relative_path: &Path,
-    query: &str,
+    full_path: PathBuf,
+    relative_path: PathBuf,
+    query: String,
 ) -> Result<Vec<SearchResult>> {
+    Ok(tokio::task::spawn_blocking(move || {
+        search_in_file_blocking(&full_path, &relative_path, &query)
+    })
+    .await?)
+}
+
+fn search_in_file_blocking(full_path: &Path, relative_path: &Path, query: &str) -> Vec<SearchResult> {
     // Quick metadata check
-    let metadata = match fs::metadata(full_path).await {
+    let metadata = match std::fs::metadata(full_path) {
         Ok(metadata) => metadata,
-        Err(_) => return Ok(Vec::new()),
+        Err(_) => return Vec::new(),
     };
 
     // Skip very large files
     if metadata.len() > 10_000_000 {
-        return Ok(Vec::new());
+        return Vec::new();
     }
 
     // Skip binary files based on extension
@@ -275,37 +406,32 @@ async fn search_in_file_optimized(
             | "mp3" | "mp4" | "avi" | "mkv" | "wav" | "flac" | "ogg"
             | "zip" | "tar" | "gz" | "7z" | "rar" | "pdf" | "class" | "jar"
         ) {
-            return Ok(Vec::new());
+            return Vec::new();
         }
     }
 
     // Read file content
-    let mut file = match fs::File::open(full_path).await {
-        Ok(f) => f,
-        Err(_) => return Ok(Vec::new()),
+    let content = match std::fs::read_to_string(full_path) {
+        Ok(content) => content,
+        Err(_) => return Vec::new(),
     };
 
-    let mut content = String::new();
-    if file.read_to_string(&mut content).await.is_err() {
-        return Ok(Vec::new());
-    }
-
     // Early return if file is too large after reading
     if content.len() > 5_000_000 {
-        return Ok(Vec::new());
+        return Vec::new();
     }
 
     // Optimized search
     let mut results = Vec::new();
     let query_lower = query.to_lowercase();
-    
+
     // Use lines iterator which is more efficient
     for (line_number, line) in content.lines().enumerate() {
         // Skip very long lines that are likely binary or generated
         if line.len() > 1000 {
             continue;
         }
-        
+
         let line_lower = line.to_lowercase();
         if let Some(start) = line_lower.find(&query_lower) {
             results.push(SearchResult {
@@ -315,7 +441,7 @@ async fn search_in_file_optimized(
                 match_start: start,
                 match_end: start + query.len(),
             });
-            
+
             // Limit results per file to prevent memory bloat
             if results.len() > 100 {
                 break;
@@ -323,5 +449,5 @@ async fn search_in_file_optimized(
         }
     }
 
-    Ok(results)
+    results
 }
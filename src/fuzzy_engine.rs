@@ -0,0 +1,110 @@
+use nucleo::pattern::{CaseMatching, Normalization};
+use nucleo::{Config, Matcher, Nucleo};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// A fuzzy match against the streaming file index: the matched path, its
+/// nucleo score (higher is better), and the byte offsets into the path's
+/// display string that should be highlighted.
+#[derive(Debug, Clone)]
+pub struct FuzzyMatch {
+    pub path: PathBuf,
+    pub score: i64,
+    pub indices: Vec<usize>,
+}
+
+/// A long-lived streaming fuzzy matcher over the file list, built on nucleo.
+///
+/// Unlike the old one-shot `SkimMatcherV2` pass, items are pushed into the
+/// engine once (via `repopulate`) and persist across queries: each keystroke
+/// just reparses the pattern and re-ticks the match engine instead of
+/// rescoring every file from scratch, and there's no hard cap on how many
+/// results can come back.
+pub struct FuzzyEngine {
+    nucleo: Nucleo<PathBuf>,
+    matcher: Matcher,
+}
+
+impl FuzzyEngine {
+    pub fn new() -> Self {
+        // Single "path" column; no secondary ranking columns needed here.
+        let nucleo = Nucleo::new(Config::DEFAULT, Arc::new(|| {}), None, 1);
+        Self { nucleo, matcher: Matcher::new(Config::DEFAULT) }
+    }
+
+    /// Replaces the full item set, e.g. after a filesystem refresh.
+    pub fn repopulate(&mut self, files: &[PathBuf]) {
+        self.nucleo.restart(true);
+        let injector = self.nucleo.injector();
+        for file in files {
+            let file = file.clone();
+            injector.push(file, |path, columns| {
+                columns[0] = path.to_string_lossy().to_string().into();
+            });
+        }
+    }
+
+    fn set_pattern(&mut self, pattern: &str) {
+        self.nucleo
+            .pattern
+            .reparse(0, pattern, CaseMatching::Smart, Normalization::Smart, false);
+    }
+
+    /// Sets `pattern`, drives the match engine until it settles (bounded by
+    /// `max_ticks` so a pathological pattern can't hang the caller), and
+    /// returns the current best-to-worst snapshot with per-match highlight
+    /// indices.
+    pub fn search(&mut self, pattern: &str, max_ticks: usize) -> Vec<FuzzyMatch> {
+        self.set_pattern(pattern);
+
+        for _ in 0..max_ticks {
+            let status = self.nucleo.tick(10);
+            if !status.running {
+                break;
+            }
+        }
+
+        let snapshot = self.nucleo.snapshot();
+        let mut indices = Vec::new();
+
+        snapshot
+            .matched_items(..)
+            .map(|item| {
+                indices.clear();
+                let haystack = item.matcher_columns[0].slice(..);
+                let score = snapshot
+                    .pattern()
+                    .column_pattern(0)
+                    .indices(haystack, &mut self.matcher, &mut indices)
+                    .unwrap_or(0);
+
+                indices.sort_unstable();
+                indices.dedup();
+
+                // `indices` are codepoint offsets into `haystack`, which only
+                // line up with byte offsets for pure-ASCII paths. Map them
+                // back to real byte offsets against the same display string
+                // `haystack` was built from, since callers highlight by
+                // byte-slicing that string.
+                let display = item.data.to_string_lossy();
+                let byte_indices = char_indices_to_byte_offsets(&display, &indices);
+
+                FuzzyMatch {
+                    path: item.data.clone(),
+                    score: score as i64,
+                    indices: byte_indices,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Converts codepoint offsets (as returned by nucleo's `indices()`) into
+/// byte offsets into `text`, so they can be used for byte-range slicing.
+fn char_indices_to_byte_offsets(text: &str, char_indices: &[u32]) -> Vec<usize> {
+    let byte_offsets: Vec<usize> = text.char_indices().map(|(byte_offset, _)| byte_offset).collect();
+    char_indices
+        .iter()
+        .filter_map(|&i| byte_offsets.get(i as usize).copied())
+        .collect()
+}
@@ -1,8 +1,14 @@
 mod app;
+mod bookmarks;
 mod file_searcher;
+mod fuzzy_engine;
+mod pager;
+mod preview;
+mod result_search;
 mod ui;
 mod tui;
 mod event;
+mod watcher;
 
 use anyhow::Result;
 use clap::Parser;
@@ -31,11 +37,13 @@ async fn main() -> Result<()> {
     let mut tui = Tui::new(backend)?;
     tui.init()?;
 
-    // Initialize application state
-    let mut app = App::new(args.directory, args.pattern).await?;
+    // Event handler (also owns the directory watcher, see event.rs)
+    let mut events = EventHandler::new(250, args.directory.clone());
+    let watch_flag = events.watch_flag();
+    let event_tx = events.sender();
 
-    // Event handler
-    let mut events = EventHandler::new(250);
+    // Initialize application state
+    let mut app = App::new(args.directory, args.pattern, watch_flag, event_tx).await?;
 
     loop {
         tui.draw(|f| ui::render(f, &mut app))?;
@@ -49,9 +57,17 @@ async fn main() -> Result<()> {
             }
             Event::Mouse(_) => {}
             Event::Resize(_, _) => {}
+            Event::FsChanged => app.handle_fs_changed().await?,
+            Event::SearchResults(generation, results) => app.append_search_results(generation, results),
+            Event::SearchComplete(generation) => app.finish_content_search(generation),
         }
     }
 
     tui.exit()?;
+
+    for path in &app.output_paths {
+        println!("{}", path.display());
+    }
+
     Ok(())
 }
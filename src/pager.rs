@@ -0,0 +1,189 @@
+/// How a `Pager`'s text is wrapped to the viewport width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reflow {
+    /// No wrapping; lines longer than the viewport scroll horizontally.
+    None,
+    /// Wrap at exactly `width` characters, splitting words.
+    HardWrap,
+    /// Wrap at word boundaries, falling back to a hard split for words
+    /// longer than `width`.
+    SoftWrap,
+}
+
+/// A scrollable, reflowing text viewer modeled on meli's pager: content is
+/// wrapped once per width change and `visible_lines` only ever returns the
+/// slice that's actually on screen, so arbitrarily long text stays cheap to
+/// redraw.
+pub struct Pager {
+    text: String,
+    cursor: (usize, usize), // (horizontal offset, top visible wrapped row)
+    width: usize,
+    height: usize,
+    reflow: Reflow,
+    dirty: bool,
+    show_scrollbar: bool,
+    // Each wrapped row keeps the index of the raw line it came from, so a
+    // jump-to-line request still lands in the right place after reflow.
+    wrapped: Vec<(usize, String)>,
+    wrapped_for_width: Option<usize>,
+    pending_jump_raw_line: Option<usize>,
+}
+
+impl Pager {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            cursor: (0, 0),
+            width: 0,
+            height: 0,
+            reflow: Reflow::SoftWrap,
+            dirty: true,
+            show_scrollbar: true,
+            wrapped: Vec::new(),
+            wrapped_for_width: None,
+            pending_jump_raw_line: None,
+        }
+    }
+
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        if self.width != width {
+            self.dirty = true;
+        }
+        self.width = width;
+        self.height = height;
+        self.reflow_if_needed();
+    }
+
+    pub fn set_reflow(&mut self, reflow: Reflow) {
+        if self.reflow != reflow {
+            self.reflow = reflow;
+            self.dirty = true;
+            self.reflow_if_needed();
+        }
+    }
+
+    /// Scrolls so the given raw (pre-wrap) line is roughly centered once the
+    /// next reflow pass runs.
+    pub fn jump_to_line(&mut self, raw_line: usize) {
+        self.pending_jump_raw_line = Some(raw_line);
+    }
+
+    fn reflow_if_needed(&mut self) {
+        if !self.dirty && self.wrapped_for_width == Some(self.width) {
+            return;
+        }
+
+        let width = self.width.max(1);
+        self.wrapped = match self.reflow {
+            Reflow::None => self
+                .text
+                .lines()
+                .enumerate()
+                .map(|(i, line)| (i, line.to_string()))
+                .collect(),
+            Reflow::HardWrap => wrap_lines(&self.text, width, hard_wrap_line),
+            Reflow::SoftWrap => wrap_lines(&self.text, width, soft_wrap_line),
+        };
+        self.wrapped_for_width = Some(self.width);
+        self.dirty = false;
+
+        if let Some(raw_line) = self.pending_jump_raw_line.take() {
+            if let Some(row) = self.wrapped.iter().position(|(idx, _)| *idx == raw_line) {
+                self.cursor.1 = row.saturating_sub(self.height / 2);
+            }
+        }
+    }
+
+    pub fn scroll_down(&mut self, n: usize) {
+        let max_row = self.wrapped.len().saturating_sub(1);
+        self.cursor.1 = (self.cursor.1 + n).min(max_row);
+    }
+
+    pub fn scroll_up(&mut self, n: usize) {
+        self.cursor.1 = self.cursor.1.saturating_sub(n);
+    }
+
+    pub fn scroll_left(&mut self, n: usize) {
+        self.cursor.0 = self.cursor.0.saturating_sub(n);
+    }
+
+    pub fn scroll_right(&mut self, n: usize) {
+        self.cursor.0 += n;
+    }
+
+    pub fn go_home(&mut self) {
+        self.cursor = (0, 0);
+    }
+
+    pub fn go_end(&mut self) {
+        self.cursor.1 = self.wrapped.len().saturating_sub(1);
+    }
+
+    pub fn visible_lines(&self) -> Vec<String> {
+        self.wrapped
+            .iter()
+            .skip(self.cursor.1)
+            .take(self.height)
+            .map(|(_, line)| line.chars().skip(self.cursor.0).collect())
+            .collect()
+    }
+
+    pub fn total_lines(&self) -> usize {
+        self.wrapped.len()
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    pub fn show_scrollbar(&self) -> bool {
+        self.show_scrollbar
+    }
+}
+
+fn wrap_lines(text: &str, width: usize, wrap_one: fn(&str, usize) -> Vec<String>) -> Vec<(usize, String)> {
+    text.lines()
+        .enumerate()
+        .flat_map(|(i, line)| wrap_one(line, width).into_iter().map(move |w| (i, w)))
+        .collect()
+}
+
+fn hard_wrap_line(line: &str, width: usize) -> Vec<String> {
+    if line.is_empty() {
+        return vec![String::new()];
+    }
+    let chars: Vec<char> = line.chars().collect();
+    chars.chunks(width).map(|chunk| chunk.iter().collect()).collect()
+}
+
+fn soft_wrap_line(line: &str, width: usize) -> Vec<String> {
+    if line.is_empty() {
+        return vec![String::new()];
+    }
+
+    let mut out = Vec::new();
+    let mut current = String::new();
+
+    for word in line.split(' ') {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            out.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+
+        while current.chars().count() > width {
+            let head: String = current.chars().take(width).collect();
+            let rest: String = current.chars().skip(width).collect();
+            out.push(head);
+            current = rest;
+        }
+    }
+
+    if !current.is_empty() {
+        out.push(current);
+    }
+
+    out
+}
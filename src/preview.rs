@@ -0,0 +1,122 @@
+use anyhow::Result;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style as SynStyle, Theme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+use tokio::fs;
+use tokio::sync::Mutex;
+
+// Files larger than this are shown as a notice instead of being highlighted,
+// mirroring the size guard in search_in_file_blocking.
+const MAX_PREVIEW_SIZE: u64 = 5_000_000;
+
+struct CachedPreview {
+    mtime: SystemTime,
+    lines: Arc<Vec<Line<'static>>>,
+}
+
+/// Renders syntax-highlighted previews of files, off the UI thread and
+/// cached per path + mtime so re-selecting a file is instant.
+pub struct Previewer {
+    syntax_set: Arc<SyntaxSet>,
+    theme: Arc<Theme>,
+    cache: Arc<Mutex<HashMap<PathBuf, CachedPreview>>>,
+}
+
+impl Previewer {
+    pub fn new() -> Self {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set
+            .themes
+            .get("base16-ocean.dark")
+            .cloned()
+            .unwrap_or_else(|| theme_set.themes.values().next().cloned().unwrap());
+
+        Self {
+            syntax_set: Arc::new(syntax_set),
+            theme: Arc::new(theme),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Highlights `full_path`, reusing the cached result if the file hasn't
+    /// been modified since it was last rendered.
+    pub async fn highlight_file(&self, full_path: &Path) -> Result<Arc<Vec<Line<'static>>>> {
+        let metadata = fs::metadata(full_path).await?;
+        let mtime = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+
+        {
+            let cache = self.cache.lock().await;
+            if let Some(cached) = cache.get(full_path) {
+                if cached.mtime == mtime {
+                    return Ok(Arc::clone(&cached.lines));
+                }
+            }
+        }
+
+        if metadata.len() > MAX_PREVIEW_SIZE {
+            let lines = Arc::new(vec![Line::from("File too large to preview")]);
+            self.cache.lock().await.insert(
+                full_path.to_path_buf(),
+                CachedPreview { mtime, lines: Arc::clone(&lines) },
+            );
+            return Ok(lines);
+        }
+
+        let content = match fs::read_to_string(full_path).await {
+            Ok(content) => content,
+            Err(_) => return Ok(Arc::new(vec![Line::from("<binary or unreadable file>")])),
+        };
+
+        let syntax_set = Arc::clone(&self.syntax_set);
+        let theme = Arc::clone(&self.theme);
+        let path = full_path.to_path_buf();
+
+        let lines = tokio::task::spawn_blocking(move || highlight_content(&syntax_set, &theme, &path, &content))
+            .await?;
+        let lines = Arc::new(lines);
+
+        self.cache.lock().await.insert(
+            full_path.to_path_buf(),
+            CachedPreview { mtime, lines: Arc::clone(&lines) },
+        );
+
+        Ok(lines)
+    }
+}
+
+fn highlight_content(syntax_set: &SyntaxSet, theme: &Theme, path: &Path, content: &str) -> Vec<Line<'static>> {
+    let syntax = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| syntax_set.find_syntax_by_extension(ext))
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    LinesWithEndings::from(content)
+        .map(|line| {
+            let ranges = highlighter.highlight_line(line, syntax_set).unwrap_or_default();
+            let spans = ranges
+                .into_iter()
+                .map(|(style, text)| Span::styled(text.trim_end_matches(['\n', '\r']).to_string(), to_ratatui_style(style)))
+                .collect::<Vec<_>>();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn to_ratatui_style(style: SynStyle) -> Style {
+    Style::default().fg(Color::Rgb(
+        style.foreground.r,
+        style.foreground.g,
+        style.foreground.b,
+    ))
+}
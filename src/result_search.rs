@@ -0,0 +1,151 @@
+/// A single row (by index into the current result set) that matched the
+/// active in-results search term, along with every byte range that matched
+/// within that row's displayed text.
+#[derive(Debug, Clone)]
+pub struct LineMatch {
+    pub result_index: usize,
+    pub byte_ranges: Vec<(usize, usize)>,
+}
+
+/// Incremental search over the currently loaded results (as opposed to
+/// `FileSearcher`, which re-runs the search against disk). Recomputed on
+/// every keystroke against the rows the caller hands in.
+#[derive(Debug, Default)]
+pub struct SearchState {
+    pub term: String,
+    pub matches: Vec<LineMatch>,
+    pub current_match: usize,
+}
+
+impl SearchState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clear(&mut self) {
+        self.term.clear();
+        self.matches.clear();
+        self.current_match = 0;
+    }
+
+    /// Recomputes matches for `term` against `rows`, case-insensitively.
+    pub fn recompute<'a>(&mut self, term: &str, rows: impl Iterator<Item = &'a str>) {
+        self.matches.clear();
+        self.current_match = 0;
+
+        if term.is_empty() {
+            return;
+        }
+
+        let term_lower = term.to_lowercase();
+        for (index, row) in rows.enumerate() {
+            // `to_lowercase()` isn't byte-length-preserving (e.g. `İ`, 2
+            // bytes, lowercases to `i̇`, 3 bytes), so matches are found
+            // against `row_lower` but translated back to byte ranges in
+            // the original `row` via `byte_map` before being stored —
+            // callers slice `row`, not `row_lower`.
+            let (row_lower, byte_map) = lowercase_with_byte_map(row);
+            let mut ranges = Vec::new();
+            let mut start = 0;
+
+            while start < row_lower.len() {
+                match row_lower[start..].find(&term_lower) {
+                    Some(pos) => {
+                        let match_start = start + pos;
+                        let match_end = match_start + term_lower.len();
+
+                        let orig_start = byte_map[match_start];
+                        let orig_end = if match_end < byte_map.len() {
+                            let end = byte_map[match_end];
+                            if end == orig_start {
+                                // `match_end` landed inside the same
+                                // original character's expansion as
+                                // `match_start` (only possible when that
+                                // character lowercases to more bytes than
+                                // it has); consume the whole character
+                                // rather than slicing into its middle.
+                                row[orig_start..]
+                                    .chars()
+                                    .next()
+                                    .map(|c| orig_start + c.len_utf8())
+                                    .unwrap_or(row.len())
+                            } else {
+                                end
+                            }
+                        } else {
+                            row.len()
+                        };
+
+                        ranges.push((orig_start, orig_end));
+                        start = match_end.max(match_start + 1);
+                    }
+                    None => break,
+                }
+            }
+
+            if !ranges.is_empty() {
+                self.matches.push(LineMatch { result_index: index, byte_ranges: ranges });
+            }
+        }
+    }
+
+    pub fn current(&self) -> Option<&LineMatch> {
+        self.matches.get(self.current_match)
+    }
+
+    pub fn advance(&mut self) -> Option<&LineMatch> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        self.current_match = (self.current_match + 1) % self.matches.len();
+        self.current()
+    }
+
+    pub fn retreat(&mut self) -> Option<&LineMatch> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        self.current_match = if self.current_match == 0 {
+            self.matches.len() - 1
+        } else {
+            self.current_match - 1
+        };
+        self.current()
+    }
+
+    pub fn ranges_for(&self, result_index: usize) -> Option<&[(usize, usize)]> {
+        self.matches
+            .iter()
+            .find(|m| m.result_index == result_index)
+            .map(|m| m.byte_ranges.as_slice())
+    }
+
+    pub fn status(&self) -> Option<String> {
+        if self.term.is_empty() || self.matches.is_empty() {
+            None
+        } else {
+            Some(format!("match {}/{}", self.current_match + 1, self.matches.len()))
+        }
+    }
+}
+
+/// Lowercases `original` and returns it alongside a byte-offset map: for
+/// every byte of the lowercased string, the byte offset in `original` of
+/// the character that produced it. Needed because `str::to_lowercase` can
+/// grow a character's byte length (e.g. `İ` → `i̇`), so byte offsets found
+/// in the lowercased string don't line up with `original` without this.
+fn lowercase_with_byte_map(original: &str) -> (String, Vec<usize>) {
+    let mut lower = String::with_capacity(original.len());
+    let mut byte_map = Vec::with_capacity(original.len());
+
+    for (orig_offset, ch) in original.char_indices() {
+        for lower_ch in ch.to_lowercase() {
+            for _ in 0..lower_ch.len_utf8() {
+                byte_map.push(orig_offset);
+            }
+            lower.push(lower_ch);
+        }
+    }
+
+    (lower, byte_map)
+}
@@ -25,6 +25,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     match app.mode {
         AppMode::FileBrowser => render_file_browser(frame, chunks[1], app),
         AppMode::ContentSearch => render_content_search(frame, chunks[1], app),
+        AppMode::Preview => render_preview(frame, chunks[1], app),
         AppMode::Help => render_help(frame, chunks[1]),
     }
 
@@ -35,6 +36,7 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     let title = match app.mode {
         AppMode::FileBrowser => "File Browser",
         AppMode::ContentSearch => "Content Search",
+        AppMode::Preview => "Preview",
         AppMode::Help => "Help",
     };
 
@@ -44,6 +46,7 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
         .border_style(match app.mode {
             AppMode::FileBrowser => Style::default().fg(Color::Green),
             AppMode::ContentSearch => Style::default().fg(Color::Blue),
+            AppMode::Preview => Style::default().fg(Color::Magenta),
             AppMode::Help => Style::default().fg(Color::Yellow),
         });
 
@@ -55,9 +58,25 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_search_input(frame: &mut Frame, area: Rect, app: &App) {
+    if app.input_mode == InputMode::ResultSearch {
+        let block = Block::default()
+            .title(" Find in results (n/N to navigate, Enter/Esc to close) ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta));
+
+        let input = Paragraph::new(app.result_search.term.as_str())
+            .block(block)
+            .style(Style::default().fg(Color::Magenta));
+
+        frame.render_widget(input, area);
+        frame.set_cursor_position((area.x + app.result_search.term.len() as u16 + 1, area.y + 1));
+        return;
+    }
+
     let input_style = match app.input_mode {
         InputMode::Normal => Style::default(),
         InputMode::Editing => Style::default().fg(Color::Yellow),
+        InputMode::ResultSearch => unreachable!(),
     };
 
     let input_block = Block::default()
@@ -76,6 +95,35 @@ fn render_search_input(frame: &mut Frame, area: Rect, app: &App) {
     }
 }
 
+/// Splits `text` into spans, rendering the given byte ranges with
+/// `highlight_style` and everything else with `base_style`.
+fn spans_with_highlights(
+    text: &str,
+    ranges: &[(usize, usize)],
+    base_style: Style,
+    highlight_style: Style,
+) -> Vec<Span<'static>> {
+    if ranges.is_empty() {
+        return vec![Span::styled(text.to_string(), base_style)];
+    }
+
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for &(start, end) in ranges {
+        if start > last && start <= text.len() {
+            spans.push(Span::styled(text[last..start].to_string(), base_style));
+        }
+        if end <= text.len() {
+            spans.push(Span::styled(text[start..end].to_string(), highlight_style));
+            last = end;
+        }
+    }
+    if last < text.len() {
+        spans.push(Span::styled(text[last..].to_string(), base_style));
+    }
+    spans
+}
+
 fn render_file_browser(frame: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -87,11 +135,16 @@ fn render_file_browser(frame: &mut Frame, area: Rect, app: &App) {
 
     render_search_input(frame, chunks[0], app);
 
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[1]);
+
     let (start_index, _total_items) = app.get_visible_items();
     let visible_items = app.file_results
         .iter()
         .skip(start_index)
-        .take(chunks[1].height as usize)
+        .take(body[0].height as usize)
         .enumerate()
         .map(|(i, path)| {
             let style = if start_index + i == app.selected_index {
@@ -100,7 +153,47 @@ fn render_file_browser(frame: &mut Frame, area: Rect, app: &App) {
                 Style::default()
             };
 
-            ListItem::new(path.display().to_string()).style(style)
+            let is_selected = app.selected_paths.contains(path);
+            let marker = if is_selected { "[x] " } else { "    " };
+            let marker_style = if is_selected {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            let global_index = app.current_page * app.page_size + start_index + i;
+            let text = path.display().to_string();
+            // `indices` are byte offsets of matched char starts; widen each to
+            // that char's full byte span so highlighting never lands on a
+            // non-ASCII character's interior byte boundary.
+            let fuzzy_ranges: Vec<(usize, usize)> = app
+                .file_match_indices
+                .get(start_index + i)
+                .map(|indices| {
+                    indices
+                        .iter()
+                        .filter_map(|&idx| text[idx..].chars().next().map(|ch| (idx, idx + ch.len_utf8())))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let mut spans = vec![Span::styled(marker, marker_style)];
+            spans.extend(match app.result_search.ranges_for(global_index) {
+                Some(ranges) => spans_with_highlights(
+                    &text,
+                    ranges,
+                    Style::default(),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+                None => spans_with_highlights(
+                    &text,
+                    &fuzzy_ranges,
+                    Style::default(),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+            });
+
+            ListItem::new(Line::from(spans)).style(style)
         })
         .collect::<Vec<_>>();
 
@@ -115,7 +208,8 @@ fn render_file_browser(frame: &mut Frame, area: Rect, app: &App) {
         )
         .highlight_style(Style::default().bg(Color::DarkGray));
 
-    frame.render_widget(list, chunks[1]);
+    frame.render_widget(list, body[0]);
+    render_preview_pane(frame, body[1], app);
 }
 
 fn render_content_search(frame: &mut Frame, area: Rect, app: &App) {
@@ -129,11 +223,16 @@ fn render_content_search(frame: &mut Frame, area: Rect, app: &App) {
 
     render_search_input(frame, chunks[0], app);
 
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[1]);
+
     let (start_index, _total_items) = app.get_visible_items();
     let visible_items = app.content_results
         .iter()
         .skip(start_index)
-        .take(chunks[1].height as usize)
+        .take(body[0].height as usize)
         .enumerate()
         .map(|(i, result)| {
             let style = if start_index + i == app.selected_index {
@@ -142,21 +241,45 @@ fn render_content_search(frame: &mut Frame, area: Rect, app: &App) {
                 Style::default()
             };
 
-            let mut spans = Vec::new();
+            let global_index = app.current_page * app.page_size + start_index + i;
             let line = &result.line_content;
-            if result.match_start > 0 {
-                spans.push(Span::raw(&line[..result.match_start]));
-            }
-            spans.push(Span::styled(
-                &line[result.match_start..result.match_end],
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            ));
-            if result.match_end < line.len() {
-                spans.push(Span::raw(&line[result.match_end..]));
-            }
+
+            let spans = match app.result_search.ranges_for(global_index) {
+                Some(ranges) => spans_with_highlights(
+                    line,
+                    ranges,
+                    Style::default(),
+                    Style::default().fg(Color::Magenta).add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
+                ),
+                None => {
+                    let mut spans = Vec::new();
+                    if result.match_start > 0 {
+                        spans.push(Span::raw(line[..result.match_start].to_string()));
+                    }
+                    spans.push(Span::styled(
+                        line[result.match_start..result.match_end].to_string(),
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    ));
+                    if result.match_end < line.len() {
+                        spans.push(Span::raw(line[result.match_end..].to_string()));
+                    }
+                    spans
+                }
+            };
+
+            let is_selected = app.selected_paths.contains(&result.file_path);
+            let marker = if is_selected { "[x] " } else { "    " };
+            let marker_style = if is_selected {
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
 
             let content = format!("{}:{} ", result.file_path.display(), result.line_number);
-            let mut full_spans = vec![Span::styled(content, Style::default().fg(Color::Cyan))];
+            let mut full_spans = vec![
+                Span::styled(marker, marker_style),
+                Span::styled(content, Style::default().fg(Color::Cyan)),
+            ];
             full_spans.extend(spans);
 
             ListItem::new(Line::from(full_spans)).style(style)
@@ -174,7 +297,85 @@ fn render_content_search(frame: &mut Frame, area: Rect, app: &App) {
         )
         .highlight_style(Style::default().bg(Color::DarkGray));
 
-    frame.render_widget(list, chunks[1]);
+    frame.render_widget(list, body[0]);
+    render_preview_pane(frame, body[1], app);
+}
+
+/// The always-on syntax-highlighted preview shown next to the file/content
+/// list, tracking the current selection (see `App::update_side_preview`).
+fn render_preview_pane(frame: &mut Frame, area: Rect, app: &App) {
+    let title = app
+        .side_preview_path
+        .as_ref()
+        .map(|p| format!(" {} ", p.display()))
+        .unwrap_or_else(|| " Preview ".to_string());
+
+    let lines = app
+        .side_preview_lines
+        .iter()
+        .skip(app.side_preview_scroll)
+        .take(area.height.saturating_sub(2) as usize)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let preview = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta)),
+    );
+
+    frame.render_widget(preview, area);
+}
+
+fn render_preview(frame: &mut Frame, area: Rect, app: &mut App) {
+    let path_title = app
+        .preview_path
+        .as_ref()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "Preview".to_string());
+
+    if let Some(pager) = app.pager.as_mut() {
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let inner_height = area.height.saturating_sub(2) as usize;
+        pager.set_size(inner_width.max(1), inner_height.max(1));
+
+        let (_, top_row) = pager.cursor();
+        let title = format!(" {} ({}/{}) ", path_title, top_row + 1, pager.total_lines().max(1));
+
+        let lines = pager
+            .visible_lines()
+            .into_iter()
+            .map(Line::from)
+            .collect::<Vec<_>>();
+
+        let preview = Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta)),
+        );
+
+        frame.render_widget(preview, area);
+        return;
+    }
+
+    let visible_lines = app
+        .preview_lines
+        .iter()
+        .skip(app.preview_scroll)
+        .take(area.height.saturating_sub(2) as usize)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let preview = Paragraph::new(visible_lines).block(
+        Block::default()
+            .title(format!(" {} ", path_title))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta)),
+    );
+
+    frame.render_widget(preview, area);
 }
 
 fn render_help(frame: &mut Frame, area: Rect) {
@@ -188,11 +389,29 @@ fn render_help(frame: &mut Frame, area: Rect) {
         Line::from("  /            Start search (live for files)"),
         Line::from("  Enter        Execute content search"),
         Line::from("  Esc          Cancel search"),
+        Line::from("  f            Find within the loaded results"),
+        Line::from("  n/N          Jump to next/previous in-results match"),
+        Line::from(""),
+        Line::from(vec![Span::styled("Selection:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+        Line::from("  Space        Toggle selection of current item"),
+        Line::from("  i            Invert selection"),
+        Line::from("  c            Clear selection"),
+        Line::from("  d            Move selected files to trash"),
+        Line::from("  y            Copy selected paths to print on exit"),
+        Line::from(""),
+        Line::from(vec![Span::styled("Bookmarks:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+        Line::from("  m<letter>    Mark current location"),
+        Line::from("  '<letter>    Jump back to a mark"),
         Line::from(""),
         Line::from(vec![Span::styled("Modes:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
         Line::from("  Tab          Switch between File/Content modes"),
         Line::from("  h/F1         Toggle help"),
         Line::from("  r            Refresh/reload files"),
+        Line::from("  w            Pause/resume live directory watching"),
+        Line::from("  s            Cycle sort key (name/size/modified)"),
+        Line::from("  S            Toggle sort order (asc/desc)"),
+        Line::from("  Enter        Preview the selected file or hit (empty search box)"),
+        Line::from("  Esc          Leave preview"),
         Line::from(""),
         Line::from(vec![Span::styled("General:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
         Line::from("  q            Quit application"),
@@ -203,7 +422,14 @@ fn render_help(frame: &mut Frame, area: Rect) {
         Line::from("• Respects .gitignore files"),
         Line::from("• Async/concurrent file processing"),
         Line::from("• Highlight matches in search"),
+        Line::from("• Highlight fuzzy-matched characters in filenames"),
+        Line::from("• Syntax-highlighted preview pane alongside results"),
+        Line::from("• Sort the file listing by name, size, or modified time"),
         Line::from("• Caching for improved performance"),
+        Line::from("• Live directory watching with auto-refresh"),
+        Line::from("• Bookmark locations and jump back by key"),
+        Line::from("• Content search results stream in as they're found"),
+        Line::from("• Scan progress shown while content search is running"),
     ];
 
     let help = Paragraph::new(help_text)
@@ -220,17 +446,21 @@ fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
     let mode_indicator = match app.mode {
         AppMode::FileBrowser => "[FILES]",
         AppMode::ContentSearch => "[SEARCH]",
+        AppMode::Preview => "[PREVIEW]",
         AppMode::Help => "[HELP]",
     };
 
     let input_indicator = match app.input_mode {
         InputMode::Normal => "NORMAL",
         InputMode::Editing => "EDITING",
+        InputMode::ResultSearch => "FIND",
     };
 
+    let watch_indicator = if app.is_watching() { "WATCH" } else { "WATCH:OFF" };
+
     let footer_text = format!(
-        " {} | {} | Tab: Switch Mode | /: Search | r: Refresh | q: Quit | h: Help ",
-        mode_indicator, input_indicator
+        " {} | {} | {} | Tab: Switch Mode | /: Search | r: Refresh | w: Watch | q: Quit | h: Help ",
+        mode_indicator, input_indicator, watch_indicator
     );
 
     let footer = Paragraph::new(footer_text)
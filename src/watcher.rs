@@ -0,0 +1,114 @@
+use anyhow::Result;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::WalkBuilder;
+use notify::{Event as NotifyEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Watches `root` for filesystem changes and funnels them through an async
+/// channel that `drain` empties without blocking (the background task in
+/// `EventHandler::new` polls it and turns bursts into `Event::FsChanged`).
+/// Watching can be paused (e.g. for very large trees) without tearing down
+/// the underlying OS watch.
+pub struct DirWatcher {
+    _watcher: RecommendedWatcher,
+    rx: mpsc::UnboundedReceiver<NotifyEvent>,
+    paused: Arc<AtomicBool>,
+    // Built by `build_ignore_matcher`, which walks the tree the same way
+    // `build_file_list` does and registers every `.gitignore` it finds
+    // (not just the root's), so a change under `.git/`, `target/`, or a
+    // nested-package's ignored `node_modules` doesn't trigger a full
+    // re-walk. All rules are matched relative to the root (see there).
+    ignore_matcher: Gitignore,
+}
+
+impl DirWatcher {
+    pub fn new(root: &Path) -> Result<Self> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let paused = Arc::new(AtomicBool::new(false));
+        let paused_for_callback = Arc::clone(&paused);
+
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<NotifyEvent>| {
+            if paused_for_callback.load(Ordering::Relaxed) {
+                return;
+            }
+            if let Ok(event) = result {
+                let _ = tx.send(event);
+            }
+        })?;
+
+        watcher.watch(root, RecursiveMode::Recursive)?;
+
+        let ignore_matcher = build_ignore_matcher(root);
+
+        Ok(Self { _watcher: watcher, rx, paused, ignore_matcher })
+    }
+
+    /// A shared handle to the pause flag, so an owner that moves the
+    /// watcher into a background task can still toggle it from outside.
+    pub fn paused_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.paused)
+    }
+
+    /// Drains every event queued since the last call, without blocking,
+    /// dropping any event whose paths are entirely ignored so editor swap
+    /// files, `.git/` internals, and build artifacts don't cause a rebuild.
+    pub fn drain(&mut self) -> Vec<NotifyEvent> {
+        let mut events = Vec::new();
+        while let Ok(event) = self.rx.try_recv() {
+            if event.paths.iter().all(|path| self.is_ignored(path)) {
+                continue;
+            }
+            events.push(event);
+        }
+        events
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        if path.components().any(|c| c.as_os_str() == ".git") {
+            return true;
+        }
+        let is_dir = path.is_dir();
+        self.ignore_matcher.matched_path_or_any_parents(path, is_dir).is_ignore()
+    }
+
+    pub fn toggle_paused(&self) -> bool {
+        let new_state = !self.paused.load(Ordering::Relaxed);
+        self.paused.store(new_state, Ordering::Relaxed);
+        new_state
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+}
+
+/// Walks `root` the same way `build_file_list` does and registers every
+/// `.gitignore` it finds (not just the root's) into a single matcher, so
+/// nested rules (e.g. `frontend/.gitignore` ignoring `frontend/node_modules`)
+/// are applied too. Every rule is matched relative to `root`, not to the
+/// directory of the `.gitignore` it came from: a nested rule anchored with a
+/// leading `/` or containing a `/` is matched against paths from the root,
+/// and an unanchored nested rule applies across the whole tree. This is a
+/// point-in-time snapshot taken once at watcher startup; a `.gitignore` added
+/// later isn't picked up until the watcher is restarted.
+fn build_ignore_matcher(root: &Path) -> Gitignore {
+    let mut builder = GitignoreBuilder::new(root);
+
+    let walker = WalkBuilder::new(root)
+        .hidden(false)
+        .ignore(true)
+        .git_ignore(true)
+        .max_depth(Some(10))
+        .build();
+
+    for entry in walker.filter_map(Result::ok) {
+        if entry.file_name() == ".gitignore" {
+            builder.add(entry.path());
+        }
+    }
+
+    builder.build().unwrap_or_else(|_| Gitignore::empty())
+}